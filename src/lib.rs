@@ -1,11 +1,265 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use serde::Serialize;
 use wasm_bindgen::prelude::*;
 
+// Dataset envelope schema understood by `initialize` and `apply_patch`.
+// Bump this when the envelope shape changes in a way older hosting apps
+// can't parse, so mismatched patches fail loudly instead of corrupting data.
+const CURRENT_SCHEMA_VERSION: u64 = 1;
+
+// Computes the exclusive upper bound of a prefix range by incrementing the
+// last Unicode scalar of `prefix`. Returns None when no such bound exists
+// (empty prefix, or the last char is already char::MAX), in which case the
+// caller should fall back to an unbounded range with a starts_with guard.
+fn prefix_upper_bound(prefix: &str) -> Option<String> {
+    let mut chars: Vec<char> = prefix.chars().collect();
+    let last = chars.pop()?;
+    let next = char::from_u32(last as u32 + 1)?;
+    chars.push(next);
+    Some(chars.into_iter().collect())
+}
+
+// Returns every (keyword, emojis) entry in `map` whose keyword starts with
+// `query`, using a bounded range scan instead of a full-map iteration. The
+// exact match (if any) sorts first since it is the lower bound of the range.
+fn prefix_range<'a>(
+    map: &'a BTreeMap<String, Vec<String>>,
+    query: &str,
+) -> Vec<(&'a String, &'a Vec<String>)> {
+    match prefix_upper_bound(query) {
+        Some(upper) => map.range(query.to_string()..upper).collect(),
+        None => map
+            .range(query.to_string()..)
+            .take_while(|(keyword, _)| keyword.starts_with(query))
+            .collect(),
+    }
+}
+
+// Computes the Damerau-Levenshtein distance between `a` and `b`, returning
+// None if it exceeds `max_distance`. Aborts early as soon as an entire row's
+// minimum cell exceeds the budget, which keeps rejecting far-off keywords
+// cheap (O(len) instead of O(len^2)); the final cell is still checked
+// against `max_distance` since every row's minimum staying within the
+// budget does not mean the final distance does.
+fn damerau_levenshtein(a: &[char], b: &[char], max_distance: usize) -> Option<usize> {
+    let la = a.len();
+    let lb = b.len();
+    let mut d = vec![vec![0usize; lb + 1]; la + 1];
+    for (i, row) in d.iter_mut().enumerate().take(la + 1) {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=la {
+        let mut row_min = d[i][0];
+        for j in 1..=lb {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            let mut val = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                val = val.min(d[i - 2][j - 2] + 1);
+            }
+            d[i][j] = val;
+            row_min = row_min.min(val);
+        }
+        if row_min > max_distance {
+            return None;
+        }
+    }
+
+    let distance = d[la][lb];
+    if distance <= max_distance {
+        Some(distance)
+    } else {
+        None
+    }
+}
+
+// Parses an Accept-Language header (e.g. "en-US,en;q=0.8,de;q=0.5") into
+// lowercased language tags ordered from highest to lowest quality weight.
+// Entries without an explicit "q=" weight default to 1.0; malformed weights
+// also default to 1.0 rather than rejecting the whole entry.
+fn parse_accept_language(header: &str) -> Vec<String> {
+    let mut entries: Vec<(String, f32)> = Vec::new();
+
+    for part in header.split(',') {
+        let part = part.trim();
+        if part.is_empty() {
+            continue;
+        }
+
+        let mut pieces = part.split(';');
+        let tag = pieces.next().unwrap_or("").trim().to_lowercase();
+        if tag.is_empty() {
+            continue;
+        }
+
+        let mut quality = 1.0f32;
+        for piece in pieces {
+            let piece = piece.trim();
+            if let Some(q) = piece.strip_prefix("q=") {
+                if let Ok(parsed) = q.parse::<f32>() {
+                    quality = parsed;
+                }
+            }
+        }
+
+        entries.push((tag, quality));
+    }
+
+    entries.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    entries.into_iter().map(|(tag, _)| tag).collect()
+}
+
+// Expands quality-ordered BCP-47 tags into a region-fallback search order,
+// e.g. ["pt-br", "en"] becomes ["pt-br", "pt", "en"]. The undetermined "und"
+// tag carries no language information and is dropped.
+fn language_fallback_chain(tags: &[String]) -> Vec<String> {
+    let mut chain = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+
+    for tag in tags {
+        if tag == "und" {
+            continue;
+        }
+
+        if seen.insert(tag.clone()) {
+            chain.push(tag.clone());
+        }
+
+        if let Some((base, _)) = tag.split_once('-') {
+            if seen.insert(base.to_string()) {
+                chain.push(base.to_string());
+            }
+        }
+    }
+
+    chain
+}
+
+// Builds the reverse (emoji -> keywords) index for a single language from
+// its forward keyword -> emojis map.
+fn build_reverse_index(keyword_map: &BTreeMap<String, Vec<String>>) -> HashMap<String, Vec<String>> {
+    let mut reverse: HashMap<String, Vec<String>> = HashMap::new();
+    for (keyword, emojis) in keyword_map {
+        for emoji in emojis {
+            reverse.entry(emoji.clone()).or_default().push(keyword.clone());
+        }
+    }
+    reverse
+}
+
+// Checks a dataset/patch envelope's "schema" field against the version this
+// build understands. Kept separate from the wasm_bindgen entry points so the
+// check itself can be exercised without constructing a `JsValue`.
+fn check_schema(value: &serde_json::Value) -> Result<(), String> {
+    let schema = value.get("schema").and_then(|v| v.as_u64());
+    if schema == Some(CURRENT_SCHEMA_VERSION) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Unsupported schema {:?} (expected {})",
+            schema, CURRENT_SCHEMA_VERSION
+        ))
+    }
+}
+
+// A single scored search result. Field order matters: it determines the
+// JSON property order front-ends see.
+#[derive(Serialize)]
+struct ScoredHit {
+    keyword: String,
+    emojis: Vec<String>,
+    score: i64,
+    match_type: &'static str,
+}
+
+// Scores and ranks every keyword in `map` that matches `query`, returning
+// (match_rank, score, hit) triples sorted by (match_rank, score, keyword) so
+// exact matches lead, then prefix, then word-boundary/substring hits for
+// multi-word keywords. Lower match_rank and score both sort first; score
+// rewards keywords whose length is closer to the query's.
+fn scored_search(map: &BTreeMap<String, Vec<String>>, query: &str) -> Vec<(u8, i64, ScoredHit)> {
+    let mut hits: Vec<(u8, i64, ScoredHit)> = Vec::new();
+    let mut matched = std::collections::HashSet::new();
+    let length_score = |keyword: &str| (keyword.chars().count() as i64 - query.chars().count() as i64).abs();
+
+    // Exact match
+    if let Some(emojis) = map.get(query) {
+        hits.push((
+            0,
+            0,
+            ScoredHit { keyword: query.to_string(), emojis: emojis.clone(), score: 0, match_type: "exact" },
+        ));
+        matched.insert(query.to_string());
+    }
+
+    // Prefix matches via the bounded range scan from prefix_range
+    for (keyword, emojis) in prefix_range(map, query) {
+        if matched.contains(keyword) {
+            continue;
+        }
+        let score = length_score(keyword);
+        hits.push((
+            1,
+            score,
+            ScoredHit { keyword: keyword.clone(), emojis: emojis.clone(), score, match_type: "prefix" },
+        ));
+        matched.insert(keyword.clone());
+    }
+
+    // Word-boundary and substring matches for multi-word keywords. These
+    // can't be expressed as a prefix range, so this is a full scan.
+    if !query.is_empty() {
+        for (keyword, emojis) in map {
+            if matched.contains(keyword) || !keyword.contains(' ') {
+                continue;
+            }
+
+            let score = length_score(keyword);
+            if keyword.split_whitespace().any(|word| word == query) {
+                hits.push((
+                    2,
+                    score,
+                    ScoredHit { keyword: keyword.clone(), emojis: emojis.clone(), score, match_type: "word_boundary" },
+                ));
+                matched.insert(keyword.clone());
+            } else if keyword.contains(query) {
+                hits.push((
+                    3,
+                    score,
+                    ScoredHit { keyword: keyword.clone(), emojis: emojis.clone(), score, match_type: "substring" },
+                ));
+                matched.insert(keyword.clone());
+            }
+        }
+    }
+
+    hits.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)).then_with(|| a.2.keyword.cmp(&b.2.keyword)));
+    hits
+}
+
 // Main structure to hold emoji data for multiple languages
 #[wasm_bindgen]
 pub struct EmojiSearch {
-    // Maps language code to keyword->emojis mapping
-    language_keywords: HashMap<String, HashMap<String, Vec<String>>>,
+    // Maps language code to keyword->emojis mapping, sorted by keyword to
+    // support efficient prefix range scans
+    language_keywords: HashMap<String, BTreeMap<String, Vec<String>>>,
+    // Reverse index: language -> emoji -> keywords that reference it. Kept
+    // in sync with language_keywords on every mutation.
+    emoji_keywords: HashMap<String, HashMap<String, Vec<String>>>,
+    // Version tag reported by the dataset envelope for each loaded language
+    // (e.g. the Unicode CLDR/emoji release it was generated from).
+    language_versions: HashMap<String, String>,
+}
+
+impl Default for EmojiSearch {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[wasm_bindgen]
@@ -15,39 +269,58 @@ impl EmojiSearch {
     pub fn new() -> Self {
         Self {
             language_keywords: HashMap::new(),
+            emoji_keywords: HashMap::new(),
+            language_versions: HashMap::new(),
         }
     }
 
-    // Initialize with emoji data - can be called multiple times to reinitialize
+    // Initialize with a versioned dataset envelope - can be called multiple
+    // times to reinitialize. Expects
+    // `{ "schema": 1, "languages": { "<code>": { "version": "...", "keywords": { ... } } } }`.
     #[wasm_bindgen]
-    pub fn initialize(&mut self, emoji_data_json: &str) -> Result<(), JsValue> {
+    pub fn initialize(&mut self, dataset_json: &str) -> Result<(), JsValue> {
         // Clear existing data for reinitialization
         self.language_keywords.clear();
-        
-        let emoji_data: serde_json::Value = serde_json::from_str(emoji_data_json)
+        self.emoji_keywords.clear();
+        self.language_versions.clear();
+
+        let dataset: serde_json::Value = serde_json::from_str(dataset_json)
             .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
-        
-        // Process the data - each top-level key is a language code
-        if let Some(obj) = emoji_data.as_object() {
-            for (language_code, language_data) in obj {
-                if let Some(language_map) = language_data.as_object() {
-                    let mut keyword_map = HashMap::new();
-                    
-                    for (keyword, emojis) in language_map {
-                        if let Some(emoji_str) = emojis.as_str() {
-                            let emoji_vec: Vec<String> = emoji_str
-                                .split_whitespace()
-                                .map(|s| s.to_string())
-                                .collect();
-                            keyword_map.insert(keyword.to_lowercase(), emoji_vec);
-                        }
+
+        check_schema(&dataset).map_err(|e| JsValue::from_str(&e))?;
+
+        // Process the data - each key under "languages" is a language code
+        if let Some(obj) = dataset.get("languages").and_then(|v| v.as_object()) {
+            for (language_code, language_entry) in obj {
+                let Some(language_map) = language_entry.get("keywords").and_then(|v| v.as_object()) else {
+                    continue;
+                };
+
+                let mut keyword_map = BTreeMap::new();
+                for (keyword, emojis) in language_map {
+                    if let Some(emoji_str) = emojis.as_str() {
+                        let emoji_vec: Vec<String> = emoji_str
+                            .split_whitespace()
+                            .map(|s| s.to_string())
+                            .collect();
+                        keyword_map.insert(keyword.to_lowercase(), emoji_vec);
                     }
-                    
-                    self.language_keywords.insert(language_code.to_lowercase(), keyword_map);
                 }
+
+                let version = language_entry
+                    .get("version")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("")
+                    .to_string();
+
+                let language_code = language_code.to_lowercase();
+                self.emoji_keywords
+                    .insert(language_code.clone(), build_reverse_index(&keyword_map));
+                self.language_versions.insert(language_code.clone(), version);
+                self.language_keywords.insert(language_code, keyword_map);
             }
         }
-        
+
         Ok(())
     }
 
@@ -58,8 +331,8 @@ impl EmojiSearch {
             .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
         
         if let Some(language_map) = language_data.as_object() {
-            let mut keyword_map = HashMap::new();
-            
+            let mut keyword_map = BTreeMap::new();
+
             for (keyword, emojis) in language_map {
                 if let Some(emoji_str) = emojis.as_str() {
                     let emoji_vec: Vec<String> = emoji_str
@@ -69,45 +342,100 @@ impl EmojiSearch {
                     keyword_map.insert(keyword.to_lowercase(), emoji_vec);
                 }
             }
-            
-            self.language_keywords.insert(language_code.to_lowercase(), keyword_map);
+
+            let language_code = language_code.to_lowercase();
+            self.emoji_keywords
+                .insert(language_code.clone(), build_reverse_index(&keyword_map));
+            // This entry point carries no version tag, so the previous one
+            // (if any) no longer describes the data - clear it rather than
+            // let get_versions() report a stale version.
+            self.language_versions.insert(language_code.clone(), String::new());
+            self.language_keywords.insert(language_code, keyword_map);
         }
-        
+
+        Ok(())
+    }
+
+    // Apply an incremental dataset patch without a full reload:
+    // `{ "schema": 1, "languages": { "<code>": { "version": "...", "upsert": {...}, "delete": [...] } } }`.
+    // "upsert" adds or replaces keywords, "delete" removes keywords, and
+    // "version" (if present) updates the stored version tag for that language.
+    #[wasm_bindgen]
+    pub fn apply_patch(&mut self, patch_json: &str) -> Result<(), JsValue> {
+        let patch: serde_json::Value = serde_json::from_str(patch_json)
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+        check_schema(&patch).map_err(|e| JsValue::from_str(&e))?;
+
+        let languages = patch
+            .get("languages")
+            .and_then(|v| v.as_object())
+            .ok_or_else(|| JsValue::from_str("Patch is missing a \"languages\" object"))?;
+
+        for (language_code, delta) in languages {
+            let language_code = language_code.to_lowercase();
+            let keyword_map = self.language_keywords.entry(language_code.clone()).or_default();
+
+            if let Some(upsert) = delta.get("upsert").and_then(|v| v.as_object()) {
+                for (keyword, emojis) in upsert {
+                    if let Some(emoji_str) = emojis.as_str() {
+                        let emoji_vec: Vec<String> = emoji_str
+                            .split_whitespace()
+                            .map(|s| s.to_string())
+                            .collect();
+                        keyword_map.insert(keyword.to_lowercase(), emoji_vec);
+                    }
+                }
+            }
+
+            if let Some(delete) = delta.get("delete").and_then(|v| v.as_array()) {
+                for keyword in delete.iter().filter_map(|k| k.as_str()) {
+                    keyword_map.remove(&keyword.to_lowercase());
+                }
+            }
+
+            // Rebuilding the whole reverse index is simpler than tracking
+            // per-keyword reverse updates, and patches are expected to be
+            // small relative to a full dataset.
+            self.emoji_keywords
+                .insert(language_code.clone(), build_reverse_index(keyword_map));
+
+            if let Some(version) = delta.get("version").and_then(|v| v.as_str()) {
+                self.language_versions.insert(language_code, version.to_string());
+            }
+        }
+
         Ok(())
     }
 
     // Remove a language entirely
     #[wasm_bindgen]
     pub fn remove_language(&mut self, language_code: &str) {
-        self.language_keywords.remove(&language_code.to_lowercase());
+        let language_code = language_code.to_lowercase();
+        self.language_keywords.remove(&language_code);
+        self.emoji_keywords.remove(&language_code);
+        self.language_versions.remove(&language_code);
     }
 
-    // Search for emojis in a specific language
+    // Search for emojis in a specific language. Results are deterministically
+    // ranked (exact, then prefix, then word-boundary/substring matches) and
+    // optionally capped to the top `limit` hits for autocomplete-style UIs.
     #[wasm_bindgen]
-    pub fn search(&self, query: &str, language: &str) -> String {
+    pub fn search(&self, query: &str, language: &str, limit: Option<u32>) -> String {
         let query = query.to_lowercase();
         let language = language.to_lowercase();
-        
+
         let map = match self.language_keywords.get(&language) {
             Some(map) => map,
             None => return "[]".to_string(), // Language not found
         };
-        
-        let mut results: Vec<(String, Vec<String>)> = Vec::new();
-        
-        // First look for exact matches
-        if let Some(emojis) = map.get(&query) {
-            results.push((query.clone(), emojis.clone()));
-        }
-        
-        // Then look for prefix matches
-        for (keyword, emojis) in map {
-            if keyword.starts_with(&query) && keyword != &query {
-                results.push((keyword.clone(), emojis.clone()));
-            }
+
+        let mut hits = scored_search(map, &query);
+        if let Some(limit) = limit {
+            hits.truncate(limit as usize);
         }
-        
-        // Convert results to JSON
+
+        let results: Vec<ScoredHit> = hits.into_iter().map(|(_, _, hit)| hit).collect();
         if let Ok(json) = serde_json::to_string(&results) {
             json
         } else {
@@ -115,50 +443,167 @@ impl EmojiSearch {
         }
     }
 
-    // Search across multiple languages (useful for multilingual users)
+    // Search across multiple languages (useful for multilingual users).
+    // Ranked and limited the same way as `search`, with earlier languages in
+    // `languages_json` winning ties when the same keyword appears twice.
     #[wasm_bindgen]
-    pub fn search_multiple(&self, query: &str, languages_json: &str) -> String {
+    pub fn search_multiple(&self, query: &str, languages_json: &str, limit: Option<u32>) -> String {
         let query = query.to_lowercase();
-        
+
         // Parse the languages array
         let languages: Result<Vec<String>, _> = serde_json::from_str(languages_json);
         let languages = match languages {
             Ok(langs) => langs,
             Err(_) => return "[]".to_string(),
         };
-        
-        let mut all_results: Vec<(String, Vec<String>)> = Vec::new();
+
+        let mut all_hits: Vec<(u8, i64, ScoredHit)> = Vec::new();
         let mut seen_keywords = std::collections::HashSet::new();
-        
+
         for language in languages {
             let language = language.to_lowercase();
             if let Some(map) = self.language_keywords.get(&language) {
-                // First look for exact matches
-                if let Some(emojis) = map.get(&query) {
-                    if !seen_keywords.contains(&query) {
-                        all_results.push((query.clone(), emojis.clone()));
-                        seen_keywords.insert(query.clone());
+                for (rank, score, hit) in scored_search(map, &query) {
+                    if seen_keywords.contains(&hit.keyword) {
+                        continue;
                     }
+                    seen_keywords.insert(hit.keyword.clone());
+                    all_hits.push((rank, score, hit));
                 }
-                
-                // Then look for prefix matches
-                for (keyword, emojis) in map {
-                    if keyword.starts_with(&query) && keyword != &query && !seen_keywords.contains(keyword) {
-                        all_results.push((keyword.clone(), emojis.clone()));
-                        seen_keywords.insert(keyword.clone());
+            }
+        }
+
+        all_hits.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)).then_with(|| a.2.keyword.cmp(&b.2.keyword)));
+        if let Some(limit) = limit {
+            all_hits.truncate(limit as usize);
+        }
+
+        let results: Vec<ScoredHit> = all_hits.into_iter().map(|(_, _, hit)| hit).collect();
+        if let Ok(json) = serde_json::to_string(&results) {
+            json
+        } else {
+            "[]".to_string()
+        }
+    }
+
+    // Search using a raw Accept-Language header instead of an explicit
+    // language list. Tags are prioritized by their "q=" weight and expanded
+    // with region fallback (e.g. "pt-BR" also searches "pt"), so a web app
+    // can pass the browser header straight through. Ranked and limited the
+    // same way as `search`/`search_multiple`, with higher-priority languages
+    // winning ties when the same keyword appears twice.
+    #[wasm_bindgen]
+    pub fn search_accept_language(&self, query: &str, accept_language: &str, limit: Option<u32>) -> String {
+        let query = query.to_lowercase();
+        let languages = language_fallback_chain(&parse_accept_language(accept_language));
+
+        let mut all_hits: Vec<(u8, i64, ScoredHit)> = Vec::new();
+        let mut seen_keywords = std::collections::HashSet::new();
+
+        for language in languages {
+            if let Some(map) = self.language_keywords.get(&language) {
+                for (rank, score, hit) in scored_search(map, &query) {
+                    if seen_keywords.contains(&hit.keyword) {
+                        continue;
                     }
+                    seen_keywords.insert(hit.keyword.clone());
+                    all_hits.push((rank, score, hit));
                 }
             }
         }
-        
-        // Convert results to JSON
-        if let Ok(json) = serde_json::to_string(&all_results) {
+
+        all_hits.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)).then_with(|| a.2.keyword.cmp(&b.2.keyword)));
+        if let Some(limit) = limit {
+            all_hits.truncate(limit as usize);
+        }
+
+        let results: Vec<ScoredHit> = all_hits.into_iter().map(|(_, _, hit)| hit).collect();
+        if let Ok(json) = serde_json::to_string(&results) {
+            json
+        } else {
+            "[]".to_string()
+        }
+    }
+
+    // Typo-tolerant search: returns keywords within `max_distance` edits of
+    // `query` (Damerau-Levenshtein), ranked nearest match first. Useful as a
+    // fallback when `search` finds nothing for a misspelled query.
+    #[wasm_bindgen]
+    pub fn search_fuzzy(&self, query: &str, language: &str, max_distance: u32) -> String {
+        let query = query.to_lowercase();
+        let language = language.to_lowercase();
+        let max_distance = max_distance.min(2) as usize;
+
+        let map = match self.language_keywords.get(&language) {
+            Some(map) => map,
+            None => return "[]".to_string(), // Language not found
+        };
+
+        let query_chars: Vec<char> = query.chars().collect();
+        let mut results: Vec<(String, Vec<String>, usize)> = Vec::new();
+
+        for (keyword, emojis) in map {
+            let keyword_chars: Vec<char> = keyword.chars().collect();
+            // Cheap pre-filter: a length gap bigger than max_distance can
+            // never yield a distance within the budget.
+            if keyword_chars.len().abs_diff(query_chars.len()) > max_distance {
+                continue;
+            }
+
+            if let Some(distance) = damerau_levenshtein(&query_chars, &keyword_chars, max_distance) {
+                results.push((keyword.clone(), emojis.clone(), distance));
+            }
+        }
+
+        // Exact and near matches lead; ties broken lexicographically
+        results.sort_by(|a, b| a.2.cmp(&b.2).then_with(|| a.0.cmp(&b.0)));
+
+        if let Ok(json) = serde_json::to_string(&results) {
             json
         } else {
             "[]".to_string()
         }
     }
 
+    // Look up the keywords associated with an emoji in a specific language
+    // (e.g. "what does \u{1fae0} mean?")
+    #[wasm_bindgen]
+    pub fn lookup_emoji(&self, emoji: &str, language: &str) -> String {
+        let language = language.to_lowercase();
+
+        let keywords = self
+            .emoji_keywords
+            .get(&language)
+            .and_then(|map| map.get(emoji))
+            .cloned()
+            .unwrap_or_default();
+
+        if let Ok(json) = serde_json::to_string(&keywords) {
+            json
+        } else {
+            "[]".to_string()
+        }
+    }
+
+    // Look up the keywords associated with an emoji across every loaded
+    // language, returned as a `{language: [keywords]}` JSON object
+    #[wasm_bindgen]
+    pub fn lookup_emoji_all(&self, emoji: &str) -> String {
+        let mut result: HashMap<&String, &Vec<String>> = HashMap::new();
+
+        for (language, reverse_map) in &self.emoji_keywords {
+            if let Some(keywords) = reverse_map.get(emoji) {
+                result.insert(language, keywords);
+            }
+        }
+
+        if let Ok(json) = serde_json::to_string(&result) {
+            json
+        } else {
+            "{}".to_string()
+        }
+    }
+
     // Get list of available languages
     #[wasm_bindgen]
     pub fn get_languages(&self) -> String {
@@ -170,6 +615,16 @@ impl EmojiSearch {
         }
     }
 
+    // Get the version tag reported for each loaded language, as `{language: version}`
+    #[wasm_bindgen]
+    pub fn get_versions(&self) -> String {
+        if let Ok(json) = serde_json::to_string(&self.language_versions) {
+            json
+        } else {
+            "{}".to_string()
+        }
+    }
+
     // Get statistics about loaded data
     #[wasm_bindgen]
     pub fn get_stats(&self) -> String {
@@ -184,4 +639,206 @@ impl EmojiSearch {
             "{}".to_string()
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_search() -> EmojiSearch {
+        let mut search = EmojiSearch::new();
+        search
+            .initialize(
+                r#"{"schema":1,"languages":{"en":{"version":"1","keywords":{
+                    "smile":"😀","smiling":"😊","aa":"🅰️"
+                }}}}"#,
+            )
+            .unwrap();
+        search
+    }
+
+    #[test]
+    fn damerau_levenshtein_rejects_distance_beyond_budget() {
+        let a: Vec<char> = "b".chars().collect();
+        let b: Vec<char> = "aa".chars().collect();
+        assert_eq!(damerau_levenshtein(&a, &b, 1), None);
+        assert_eq!(damerau_levenshtein(&a, &b, 2), Some(2));
+    }
+
+    #[test]
+    fn search_fuzzy_excludes_matches_beyond_max_distance() {
+        let search = sample_search();
+        let json = search.search_fuzzy("b", "en", 1);
+        let results: Vec<(String, Vec<String>, usize)> = serde_json::from_str(&json).unwrap();
+        assert!(results.is_empty(), "expected no matches within distance 1, got {:?}", results);
+    }
+
+    #[test]
+    fn search_fuzzy_finds_typo_within_budget() {
+        let search = sample_search();
+        let json = search.search_fuzzy("smilng", "en", 2);
+        let results: Vec<(String, Vec<String>, usize)> = serde_json::from_str(&json).unwrap();
+        assert_eq!(results[0].0, "smiling");
+    }
+
+    fn bilingual_search() -> EmojiSearch {
+        let mut search = EmojiSearch::new();
+        search
+            .initialize(
+                r#"{"schema":1,"languages":{
+                    "en":{"version":"1","keywords":{"hi":"👋"}},
+                    "de":{"version":"1","keywords":{"hi":"🙋"}}
+                }}"#,
+            )
+            .unwrap();
+        search
+    }
+
+    #[test]
+    fn parse_accept_language_orders_by_quality_weight() {
+        let tags = parse_accept_language("en-US,en;q=0.8,de;q=0.5");
+        assert_eq!(tags, vec!["en-us", "en", "de"]);
+    }
+
+    #[test]
+    fn parse_accept_language_defaults_malformed_weight_to_one() {
+        let tags = parse_accept_language("fr;q=bogus,de;q=0.9");
+        assert_eq!(tags, vec!["fr", "de"]);
+    }
+
+    #[test]
+    fn language_fallback_chain_expands_region_and_drops_und() {
+        let chain = language_fallback_chain(&["pt-br".to_string(), "und".to_string(), "en".to_string()]);
+        assert_eq!(chain, vec!["pt-br", "pt", "en"]);
+    }
+
+    #[test]
+    fn language_fallback_chain_dedups_when_base_already_present() {
+        let chain = language_fallback_chain(&["en".to_string(), "en-us".to_string()]);
+        assert_eq!(chain, vec!["en", "en-us"]);
+    }
+
+    #[test]
+    fn search_accept_language_prioritizes_higher_quality_tag() {
+        let search = bilingual_search();
+
+        let json = search.search_accept_language("hi", "de;q=0.9,en;q=0.5", None);
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed[0]["emojis"][0], "🙋");
+
+        let json = search.search_accept_language("hi", "en;q=0.9,de;q=0.5", None);
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed[0]["emojis"][0], "👋");
+    }
+
+    #[test]
+    fn lookup_emoji_returns_keywords_for_emoji() {
+        let search = sample_search();
+        let json = search.lookup_emoji("😀", "en");
+        let keywords: Vec<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(keywords, vec!["smile"]);
+    }
+
+    #[test]
+    fn lookup_emoji_all_reports_each_language() {
+        let mut search = EmojiSearch::new();
+        search
+            .initialize(
+                r#"{"schema":1,"languages":{
+                    "en":{"version":"1","keywords":{"wave":"👋"}},
+                    "de":{"version":"1","keywords":{"winken":"👋"}}
+                }}"#,
+            )
+            .unwrap();
+
+        let json = search.lookup_emoji_all("👋");
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["en"], serde_json::json!(["wave"]));
+        assert_eq!(parsed["de"], serde_json::json!(["winken"]));
+    }
+
+    #[test]
+    fn remove_language_clears_reverse_index() {
+        let mut search = sample_search();
+        search.remove_language("en");
+        let json = search.lookup_emoji("😀", "en");
+        let keywords: Vec<String> = serde_json::from_str(&json).unwrap();
+        assert!(keywords.is_empty());
+    }
+
+    fn ranking_fixture() -> EmojiSearch {
+        let mut search = EmojiSearch::new();
+        search
+            .initialize(
+                r#"{"schema":1,"languages":{"en":{"version":"1","keywords":{
+                    "cat":"🐱",
+                    "catnip treat":"🌿",
+                    "black cat":"🐈",
+                    "wildcats roam":"🐆"
+                }}}}"#,
+            )
+            .unwrap();
+        search
+    }
+
+    #[test]
+    fn scored_search_orders_by_match_kind_then_score() {
+        let search = ranking_fixture();
+        let json = search.search("cat", "en", None);
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let results = parsed.as_array().unwrap();
+
+        let keywords: Vec<&str> = results.iter().map(|h| h["keyword"].as_str().unwrap()).collect();
+        assert_eq!(keywords, vec!["cat", "catnip treat", "black cat", "wildcats roam"]);
+
+        let match_types: Vec<&str> = results.iter().map(|h| h["match_type"].as_str().unwrap()).collect();
+        assert_eq!(match_types, vec!["exact", "prefix", "word_boundary", "substring"]);
+    }
+
+    #[test]
+    fn scored_search_respects_limit() {
+        let search = ranking_fixture();
+        let json = search.search("cat", "en", Some(2));
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn check_schema_rejects_mismatched_version() {
+        let mismatched: serde_json::Value = serde_json::from_str(r#"{"schema":2,"languages":{}}"#).unwrap();
+        assert!(check_schema(&mismatched).is_err());
+
+        let matching: serde_json::Value = serde_json::from_str(r#"{"schema":1,"languages":{}}"#).unwrap();
+        assert!(check_schema(&matching).is_ok());
+    }
+
+    #[test]
+    fn apply_patch_upserts_and_deletes_keywords_and_updates_version() {
+        let mut search = sample_search();
+        search
+            .apply_patch(r#"{"schema":1,"languages":{"en":{"version":"15.2","upsert":{"grin":"😁"},"delete":["aa"]}}}"#)
+            .unwrap();
+
+        let added: serde_json::Value = serde_json::from_str(&search.search("grin", "en", None)).unwrap();
+        assert_eq!(added[0]["keyword"], "grin");
+
+        let removed: serde_json::Value = serde_json::from_str(&search.search("aa", "en", None)).unwrap();
+        assert!(removed.as_array().unwrap().is_empty());
+
+        let versions: serde_json::Value = serde_json::from_str(&search.get_versions()).unwrap();
+        assert_eq!(versions["en"], "15.2");
+
+        // The reverse index should have been rebuilt for the new keyword too
+        let lookup: Vec<String> = serde_json::from_str(&search.lookup_emoji("😁", "en")).unwrap();
+        assert_eq!(lookup, vec!["grin"]);
+    }
+
+    #[test]
+    fn update_language_clears_stale_version() {
+        let mut search = sample_search();
+        search.update_language("en", r#"{"smile":"😀"}"#).unwrap();
+
+        let versions: serde_json::Value = serde_json::from_str(&search.get_versions()).unwrap();
+        assert_eq!(versions["en"], "");
+    }
 }
\ No newline at end of file